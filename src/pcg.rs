@@ -1,4 +1,12 @@
-use rand::{Rng, SeedableRng, Rand};
+use rand_core::{impls, le, Error, RngCore, SeedableRng};
+
+#[cfg(feature = "std")]
+use rand_core::ErrorKind;
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+
+/// The default multiplier for a 64-bit PCG state, as used by `PcgRng`.
+const PCG_32_MUL_64: u64 = 6364136223846793005;
 
 /// A [PCG](http://www.pcg-random.org)-based random number generator.
 ///
@@ -9,6 +17,8 @@ use rand::{Rng, SeedableRng, Rand};
 ///
 /// This particular implementation uses a 128-bit state value, has a period of 2^64, and uses the
 /// `XSH-RR` output function.
+///
+/// This type does not depend on `std`, so it is usable in `no_std` contexts.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PcgRng {
     state: u64,
@@ -41,42 +51,471 @@ impl PcgRng {
             inc: id,
         }
     }
+
+    /// Advances the generator's state `delta` steps forward, in O(log `delta`) time.
+    ///
+    /// This is equivalent to calling `next_u32()` `delta` times and discarding the results, but
+    /// runs in logarithmic time by doubling the LCG's multiplier and increment instead of
+    /// iterating one step at a time. Passing the two's complement of a delta jumps backward
+    /// instead. This lets parallel Monte Carlo workers seek into disjoint, reproducible
+    /// sub-streams of a shared seed without having to drain the generator to get there.
+    pub fn advance(&mut self, delta: u64) {
+        let mut acc_mult: u64 = 1;
+        let mut acc_plus: u64 = 0;
+        let mut cur_mult = PCG_32_MUL_64;
+        let mut cur_plus = self.inc;
+        let mut delta = delta;
+        while delta != 0 {
+            if delta & 1 != 0 {
+                acc_mult = acc_mult.wrapping_mul(cur_mult);
+                acc_plus = acc_plus.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+            }
+            cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+            cur_mult = cur_mult.wrapping_mul(cur_mult);
+            delta >>= 1;
+        }
+        self.state = acc_mult.wrapping_mul(self.state).wrapping_add(acc_plus);
+    }
+
+    /// Returns the number of `next_u32` calls needed to advance `self` to the same state as
+    /// `other`, assuming both share the same stream (`inc`).
+    ///
+    /// # Panics
+    ///
+    /// Panics (in all build profiles) if `self` and `other` do not share a stream, since the two
+    /// states are then not guaranteed reachable from one another and the search below would
+    /// otherwise spin forever.
+    pub fn distance(&self, other: &PcgRng) -> u64 {
+        assert_eq!(
+            self.inc, other.inc,
+            "PcgRng::distance requires both generators to share a stream"
+        );
+        let mut cur_mult = PCG_32_MUL_64;
+        let mut cur_plus = self.inc;
+        let mut cur_state = self.state;
+        let mut the_bit: u64 = 1;
+        let mut distance: u64 = 0;
+        while cur_state != other.state {
+            if (cur_state & the_bit) != (other.state & the_bit) {
+                cur_state = cur_state.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+                distance |= the_bit;
+            }
+            the_bit <<= 1;
+            cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+            cur_mult = cur_mult.wrapping_mul(cur_mult);
+        }
+        distance
+    }
+
+    /// Sets `state` and `inc` directly from the two halves of a raw `[u64; 2]` seed, following
+    /// the standard PCG `srandom` construction.
+    fn reseed(&mut self, seed: [u64; 2]) {
+        self.state = 0;
+        self.inc = (seed[1] << 1) | 1;
+        self.next_u32();
+        self.state = self.state.wrapping_add(seed[0]);
+        self.next_u32();
+    }
 }
 
-impl Rng for PcgRng {
+impl RngCore for PcgRng {
     #[inline(always)]
     fn next_u32(&mut self) -> u32 {
         let old = self.state;
-        self.state = old.wrapping_mul(6364136223846793005)
+        self.state = old.wrapping_mul(PCG_32_MUL_64)
                         .wrapping_add(self.inc);
         let xor = (((old >> 18) ^ old) >> 27) as u32;
-        let rot = old >> 59 as u32;
-        let out = (xor >> rot) | (xor << (((0 as u64).wrapping_sub(rot)) & 31));
-        out
+        let rot = old >> 59_u32;
+        (xor >> rot) | (xor << ((0_u64.wrapping_sub(rot)) & 31))
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_u32(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
     }
 }
 
-impl SeedableRng<[u64; 2]> for PcgRng {
-    /// Reseed a `PcgRng`.
-    fn reseed(&mut self, seed: [u64; 2]) {
+impl SeedableRng for PcgRng {
+    type Seed = [u8; 16];
+
+    /// Create a new `PcgRng` from a 128-bit seed, the first half giving the initial state and
+    /// the second half selecting the stream.
+    fn from_seed(seed: [u8; 16]) -> PcgRng {
+        let mut seed_u64 = [0u64; 2];
+        le::read_u64_into(&seed, &mut seed_u64);
+
+        let mut rng = PcgRng::new_unseeded();
+        rng.reseed(seed_u64);
+        rng
+    }
+}
+
+#[cfg(feature = "std")]
+impl PcgRng {
+    /// Creates a new `PcgRng` by reading a 128-bit seed (16 little-endian bytes) from `r`.
+    ///
+    /// This lets a `PcgRng` be initialized from `/dev/urandom`, a file of recorded entropy, or a
+    /// network socket, which is handy for reproducible replay against externally generated seeds.
+    pub fn from_reader<R: Read>(r: &mut R) -> io::Result<PcgRng> {
+        let mut seed = [0u8; 16];
+        r.read_exact(&mut seed)?;
+        Ok(PcgRng::from_seed(seed))
+    }
+}
+
+/// The default multiplier for a 128-bit PCG state, as used by `Pcg64`.
+const PCG_64_MUL_128: u128 = 0x2360_ed05_1fc6_5da4_4385_df64_9fcc_f645;
+
+/// A [PCG](http://www.pcg-random.org)-based random number generator with a 128-bit state.
+///
+/// This is the 128-bit-state counterpart to `PcgRng`: it uses the `XSL-RR` output function to
+/// produce 64-bit output natively, rather than concatenating two 32-bit draws. Aside from the
+/// wider state and output, it behaves exactly like `PcgRng`, including support for multiple
+/// distinct _streams_ of output given a common seed.
+///
+/// This particular implementation uses a 128-bit state value, has a period of 2^128, and uses the
+/// `XSL-RR` output function.
+///
+/// This type does not depend on `std`, so it is usable in `no_std` contexts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pcg64 {
+    state: u128,
+    inc: u128,
+}
+
+impl Pcg64 {
+    /// Returns a new `Pcg64` instance which is not seeded.
+    ///
+    /// The initial values of this RNG are constants, so all generators created by this function
+    /// will yield the same stream of random numbers. It is highly recommended that this is created
+    /// through `SeedableRng` instead of this function.
+    pub fn new_unseeded() -> Pcg64 {
+        Pcg64 {
+            state: 0x979c_9a98_d846_2005_7d3e_9cb6_cfe0_549b,
+            inc: 0xda3e39cb94b95bdb,
+        }
+    }
+
+    /// Sets the stream ID of the `Pcg64`.
+    pub fn set_stream(&mut self, id: u128) {
+        self.inc = id;
+    }
+
+    /// Returns a new `Pcg64` instance with the same state as `self`, but with the given stream
+    /// ID.
+    pub fn with_stream(&self, id: u128) -> Pcg64 {
+        Pcg64 {
+            state: self.state,
+            inc: id,
+        }
+    }
+
+    /// Sets `state` and `inc` directly from the two halves of a raw `[u64; 4]` seed, following
+    /// the standard PCG `srandom` construction widened to 128 bits.
+    fn reseed(&mut self, seed: [u64; 4]) {
+        let state_seed = ((seed[0] as u128) << 64) | seed[1] as u128;
+        let stream_seed = ((seed[2] as u128) << 64) | seed[3] as u128;
         self.state = 0;
-        self.inc = (seed[1] << 1) | 1;
-        self.next_u32();
-        self.state = self.state.wrapping_add(seed[0]);
-        self.next_u32();
+        self.inc = (stream_seed << 1) | 1;
+        self.next_u64();
+        self.state = self.state.wrapping_add(state_seed);
+        self.next_u64();
     }
+}
 
-    /// Create a new `PcgRng`.
-    fn from_seed(seed: [u64; 2]) -> PcgRng {
-        let mut rng = PcgRng::new_unseeded();
-        rng.reseed(seed);
+impl RngCore for Pcg64 {
+    #[inline(always)]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_mul(PCG_64_MUL_128).wrapping_add(self.inc);
+        let rot = (self.state >> 122) as u32;
+        let xored = ((self.state >> 64) ^ self.state) as u64;
+        xored.rotate_right(rot)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for Pcg64 {
+    type Seed = [u8; 32];
+
+    /// Create a new `Pcg64` from a 256-bit seed, the first half giving the initial state and
+    /// the second half selecting the stream.
+    fn from_seed(seed: [u8; 32]) -> Pcg64 {
+        let mut seed_u64 = [0u64; 4];
+        le::read_u64_into(&seed, &mut seed_u64);
+
+        let mut rng = Pcg64::new_unseeded();
+        rng.reseed(seed_u64);
         rng
     }
 }
 
-impl Rand for PcgRng {
-    fn rand<R: Rng>(rng: &mut R) -> Self {
-        PcgRng { state: rng.next_u64(), inc: rng.next_u64() }
+/// The "cheap" multiplier used by the DXSM output function, as used by `Pcg64Dxsm`.
+const PCG_64_MUL_DXSM: u128 = 0xda942042e4dd58b5;
+
+/// A [PCG](http://www.pcg-random.org)-based random number generator using the DXSM output
+/// function.
+///
+/// This is a 128-bit-state generator like `Pcg64`, but uses the `DXSM` ("double xorshift
+/// multiply") output function instead of `XSL-RR`. `DXSM` is the output function behind NumPy's
+/// `PCG64DXSM`, so seeding a `Pcg64Dxsm` the same way as a `PCG64DXSM` (same `state`/`inc`
+/// derivation) reproduces the same stream. It also mitigates the stream correlation that `XSL-RR`
+/// can exhibit between nearby streams, at the cost of a slightly more expensive output function.
+///
+/// This particular implementation uses a 128-bit state value, has a period of 2^128, and uses the
+/// `DXSM` output function.
+///
+/// This type does not depend on `std`, so it is usable in `no_std` contexts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pcg64Dxsm {
+    state: u128,
+    inc: u128,
+}
+
+impl Pcg64Dxsm {
+    /// Returns a new `Pcg64Dxsm` instance which is not seeded.
+    ///
+    /// The initial values of this RNG are constants, so all generators created by this function
+    /// will yield the same stream of random numbers. It is highly recommended that this is created
+    /// through `SeedableRng` instead of this function.
+    pub fn new_unseeded() -> Pcg64Dxsm {
+        Pcg64Dxsm {
+            state: 0x979c_9a98_d846_2005_7d3e_9cb6_cfe0_549b,
+            inc: 0xda3e39cb94b95bdb,
+        }
+    }
+
+    /// Sets the stream ID of the `Pcg64Dxsm`.
+    pub fn set_stream(&mut self, id: u128) {
+        self.inc = id;
+    }
+
+    /// Returns a new `Pcg64Dxsm` instance with the same state as `self`, but with the given
+    /// stream ID.
+    pub fn with_stream(&self, id: u128) -> Pcg64Dxsm {
+        Pcg64Dxsm {
+            state: self.state,
+            inc: id,
+        }
+    }
+
+    /// Sets `state` and `inc` directly from the two halves of a raw `[u64; 4]` seed, following
+    /// the standard PCG `srandom` construction widened to 128 bits.
+    fn reseed(&mut self, seed: [u64; 4]) {
+        let state_seed = ((seed[0] as u128) << 64) | seed[1] as u128;
+        let stream_seed = ((seed[2] as u128) << 64) | seed[3] as u128;
+        self.state = 0;
+        self.inc = (stream_seed << 1) | 1;
+        self.next_u64();
+        self.state = self.state.wrapping_add(state_seed);
+        self.next_u64();
+    }
+}
+
+impl RngCore for Pcg64Dxsm {
+    #[inline(always)]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        let mut hi = (self.state >> 64) as u64;
+        let lo = (self.state as u64) | 1;
+        hi ^= hi >> 32;
+        hi = hi.wrapping_mul(PCG_64_MUL_DXSM as u64);
+        hi ^= hi >> 48;
+        hi = hi.wrapping_mul(lo);
+
+        self.state = self.state.wrapping_mul(PCG_64_MUL_DXSM).wrapping_add(self.inc);
+
+        hi
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for Pcg64Dxsm {
+    type Seed = [u8; 32];
+
+    /// Create a new `Pcg64Dxsm` from a 256-bit seed, the first half giving the initial state and
+    /// the second half selecting the stream.
+    fn from_seed(seed: [u8; 32]) -> Pcg64Dxsm {
+        let mut seed_u64 = [0u64; 4];
+        le::read_u64_into(&seed, &mut seed_u64);
+
+        let mut rng = Pcg64Dxsm::new_unseeded();
+        rng.reseed(seed_u64);
+        rng
+    }
+}
+
+/// An adapter that wraps a `PcgRng` and periodically reseeds it from a fallible entropy source.
+///
+/// PCG is not cryptographically secure: given enough output, its state can be recovered. For
+/// long-running simulations (and to keep forked workers from drawing correlated streams),
+/// `ReseedingPcg` bounds how far any single seed is exploited by drawing fresh entropy from `R`
+/// (e.g. `OsRng`) once `threshold` bytes have been generated, and again whenever `fork` is used to
+/// spawn an independent worker. This is analogous to `rand`'s old `ReseedingRng`, adapted to
+/// `rand_core::RngCore`.
+#[derive(Debug)]
+pub struct ReseedingPcg<R> {
+    rng: PcgRng,
+    threshold: u64,
+    bytes_generated: u64,
+    reseeder: R,
+}
+
+impl<R: RngCore> ReseedingPcg<R> {
+    /// Creates a new `ReseedingPcg` wrapping `rng`, reseeding from `reseeder` after every
+    /// `threshold` bytes of output.
+    pub fn new(rng: PcgRng, threshold: u64, reseeder: R) -> ReseedingPcg<R> {
+        ReseedingPcg {
+            rng,
+            threshold,
+            bytes_generated: 0,
+            reseeder,
+        }
+    }
+
+    /// Reseeds the inner `PcgRng` if `threshold` bytes have been generated since the last reseed.
+    ///
+    /// If `reseeder` fails to supply entropy, the inner `PcgRng` is left untouched and will be
+    /// retried on the next call that crosses the threshold.
+    pub fn reseed_if_necessary(&mut self) {
+        if self.bytes_generated >= self.threshold {
+            self.reseed();
+        }
+    }
+
+    fn reseed(&mut self) {
+        let mut seed = [0u8; 16];
+        if self.reseeder.try_fill_bytes(&mut seed).is_ok() {
+            self.rng = PcgRng::from_seed(seed);
+        }
+        self.bytes_generated = 0;
+    }
+
+    /// Forks off an independent `ReseedingPcg` that draws its own fresh seed from `reseeder`,
+    /// rather than sharing or copying the inner `PcgRng`'s current state, so that a parent and its
+    /// fork never draw a correlated stream.
+    ///
+    /// This takes `&mut self` (rather than being a `Clone` impl) because producing the fork's
+    /// seed consumes entropy from the shared `reseeder` — a `Clone::clone(&self)` could only ever
+    /// clone `reseeder`'s frozen state, handing every fork of the same parent an identical seed.
+    /// If the reseeder fails to supply entropy, the fork falls back to copying the inner state.
+    pub fn fork(&mut self) -> ReseedingPcg<R>
+    where
+        R: Clone,
+    {
+        let mut seed = [0u8; 16];
+        let rng = if self.reseeder.try_fill_bytes(&mut seed).is_ok() {
+            PcgRng::from_seed(seed)
+        } else {
+            self.rng.clone()
+        };
+        ReseedingPcg {
+            rng,
+            threshold: self.threshold,
+            bytes_generated: 0,
+            reseeder: self.reseeder.clone(),
+        }
+    }
+}
+
+impl<R: RngCore> RngCore for ReseedingPcg<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.reseed_if_necessary();
+        self.bytes_generated += 4;
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.reseed_if_necessary();
+        self.bytes_generated += 8;
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.reseed_if_necessary();
+        self.bytes_generated += dest.len() as u64;
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// An RNG that reads its output straight from a `Read`, mirroring `rand`'s old
+/// `read::ReadRng`. This works best with an effectively infinite reader (e.g. a file of recorded
+/// entropy or a CSPRNG device); a short read is surfaced as an `Error` from `try_fill_bytes`, and
+/// as a panic from the other, infallible `RngCore` methods, since they have no way to report
+/// failure.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct ReadRng<R> {
+    reader: R,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> ReadRng<R> {
+    /// Creates a new `ReadRng` that draws its output from `reader`.
+    pub fn new(reader: R) -> ReadRng<R> {
+        ReadRng { reader }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> RngCore for ReadRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest).unwrap()
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        if dest.is_empty() {
+            return Ok(());
+        }
+        self.reader
+            .read_exact(dest)
+            .map_err(|e| Error::with_cause(ErrorKind::Unavailable, "ReadRng: end of file reached", e))
     }
 }
 
@@ -84,13 +523,29 @@ impl Rand for PcgRng {
 mod test {
     use super::*;
 
-    use rand::{Rng, SeedableRng};
+    use rand_core::{ErrorKind, RngCore, SeedableRng};
+
+    fn seed16(a: u64, b: u64) -> [u8; 16] {
+        let mut seed = [0u8; 16];
+        seed[0..8].copy_from_slice(&a.to_le_bytes());
+        seed[8..16].copy_from_slice(&b.to_le_bytes());
+        seed
+    }
+
+    fn seed32(a: u64, b: u64, c: u64, d: u64) -> [u8; 32] {
+        let mut seed = [0u8; 32];
+        seed[0..8].copy_from_slice(&a.to_le_bytes());
+        seed[8..16].copy_from_slice(&b.to_le_bytes());
+        seed[16..24].copy_from_slice(&c.to_le_bytes());
+        seed[24..32].copy_from_slice(&d.to_le_bytes());
+        seed
+    }
 
     #[test]
     fn output() {
-        let mut rng = PcgRng::from_seed([42, 54]);
+        let mut rng = PcgRng::from_seed(seed16(42, 54));
 
-        let v: Vec<u32> = rng.gen_iter().take(6).collect();
+        let v: Vec<u32> = (0..6).map(|_| rng.next_u32()).collect();
 
         // test vectors from pcg32-global-demo
         assert_eq!(v,
@@ -99,7 +554,275 @@ mod test {
 
     #[test]
     fn overflow() {
-        let mut rng = PcgRng::from_seed([!0, 54]);
+        let mut rng = PcgRng::from_seed(seed16(!0, 54));
+        rng.next_u32();
+    }
+
+    #[test]
+    fn advance_matches_stepping() {
+        let mut stepped = PcgRng::from_seed(seed16(42, 54));
+        let mut advanced = stepped.clone();
+
+        for _ in 0..20 {
+            stepped.next_u32();
+        }
+        advanced.advance(20);
+
+        assert_eq!(stepped.next_u32(), advanced.next_u32());
+    }
+
+    #[test]
+    fn advance_backwards_undoes_advance() {
+        let rng = PcgRng::from_seed(seed16(42, 54));
+
+        let mut forward = rng.clone();
+        forward.advance(1000);
+        forward.advance(0u64.wrapping_sub(1000));
+
+        assert_eq!(forward.next_u32(), rng.clone().next_u32());
+    }
+
+    #[test]
+    fn distance_matches_advance() {
+        let rng = PcgRng::from_seed(seed16(42, 54));
+        let mut advanced = rng.clone();
+        advanced.advance(12345);
+
+        assert_eq!(rng.distance(&advanced), 12345);
+    }
+
+    #[test]
+    #[should_panic]
+    fn distance_panics_on_mismatched_streams() {
+        let rng = PcgRng::from_seed(seed16(42, 54));
+        let other = PcgRng::from_seed(seed16(42, 55));
+
+        // Different streams aren't guaranteed reachable from one another, so this must panic
+        // (in every build profile) rather than spin forever.
+        rng.distance(&other);
+    }
+
+    #[test]
+    fn fill_bytes_matches_next_u64() {
+        let mut rng = PcgRng::from_seed(seed16(42, 54));
+        let mut via_next = rng.clone();
+
+        let mut dest = [0u8; 24];
+        rng.fill_bytes(&mut dest);
+
+        for chunk in dest.chunks(8) {
+            assert_eq!(chunk, &via_next.next_u64().to_le_bytes());
+        }
+    }
+
+    #[test]
+    fn pcg64_output() {
+        let mut rng = Pcg64::from_seed(seed32(0, 42, 0, 54));
+
+        let v: Vec<u64> = (0..6).map(|_| rng.next_u64()).collect();
+
+        // test vectors from the reference pcg64 (XSL-RR 128/64) C++ implementation
+        assert_eq!(v,
+                   vec![0x86b1da1d72062b68,
+                        0x1304aa46c9853d39,
+                        0xa3670e9e0dd50358,
+                        0xf9090e529a7dae00,
+                        0xc85b9fd837996f2c,
+                        0x606121f8e3919196]);
+    }
+
+    #[test]
+    fn pcg64_overflow() {
+        let mut rng = Pcg64::from_seed(seed32(!0, !0, 0, 54));
+        rng.next_u64();
+    }
+
+    #[test]
+    fn pcg64_dxsm_output() {
+        let mut rng = Pcg64Dxsm::from_seed(seed32(0, 42, 0, 54));
+
+        let v: Vec<u64> = (0..6).map(|_| rng.next_u64()).collect();
+
+        // test vectors checked against NumPy's PCG64DXSM (same state/inc derivation, same
+        // `cm_setseq_dxsm_128_64` output function)
+        assert_eq!(v,
+                   vec![17331114245835578256,
+                        10267467544499227306,
+                        9726600296081716989,
+                        10165951391103677450,
+                        12131334649314727261,
+                        10134094537930450875]);
+    }
+
+    #[test]
+    fn pcg64_dxsm_overflow() {
+        let mut rng = Pcg64Dxsm::from_seed(seed32(!0, !0, 0, 54));
+        rng.next_u64();
+    }
+
+    /// A deterministic, incrementing-byte "entropy" source for exercising `ReseedingPcg` without
+    /// pulling in a real OS entropy source.
+    #[derive(Clone)]
+    struct CountingReseeder {
+        next_byte: u8,
+    }
+
+    impl RngCore for CountingReseeder {
+        fn next_u32(&mut self) -> u32 {
+            impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for b in dest.iter_mut() {
+                *b = self.next_byte;
+                self.next_byte = self.next_byte.wrapping_add(1);
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn reseeding_pcg_reseeds_after_threshold() {
+        let inner = PcgRng::from_seed(seed16(42, 54));
+        let mut rng = ReseedingPcg::new(inner, 4, CountingReseeder { next_byte: 0 });
+
+        // The threshold (4 bytes) is crossed by the first `next_u32`, so the second call should
+        // come from a `PcgRng` freshly seeded from the reseeder rather than from the original.
         rng.next_u32();
+
+        let mut expected = PcgRng::from_seed(seed16(
+            u64::from_le_bytes([0, 1, 2, 3, 4, 5, 6, 7]),
+            u64::from_le_bytes([8, 9, 10, 11, 12, 13, 14, 15]),
+        ));
+        assert_eq!(rng.next_u32(), expected.next_u32());
+    }
+
+    #[test]
+    fn reseeding_pcg_fork_draws_fresh_seed() {
+        let inner = PcgRng::from_seed(seed16(42, 54));
+        let mut rng = ReseedingPcg::new(inner, 1_000_000, CountingReseeder { next_byte: 0 });
+        let mut forked = rng.fork();
+
+        let mut expected = PcgRng::from_seed(seed16(
+            u64::from_le_bytes([0, 1, 2, 3, 4, 5, 6, 7]),
+            u64::from_le_bytes([8, 9, 10, 11, 12, 13, 14, 15]),
+        ));
+        assert_eq!(forked.next_u32(), expected.next_u32());
+    }
+
+    #[test]
+    fn reseeding_pcg_successive_forks_diverge() {
+        // Each fork must consume fresh entropy from the shared reseeder, so two forks of the same
+        // parent must not draw the same seed.
+        let inner = PcgRng::from_seed(seed16(42, 54));
+        let mut rng = ReseedingPcg::new(inner, 1_000_000, CountingReseeder { next_byte: 0 });
+
+        let mut fork1 = rng.fork();
+        let mut fork2 = rng.fork();
+
+        assert_ne!(fork1.next_u32(), fork2.next_u32());
+    }
+
+    /// An entropy source that always fails, for exercising `ReseedingPcg`'s failure handling.
+    #[derive(Clone)]
+    struct FailingReseeder;
+
+    impl RngCore for FailingReseeder {
+        fn next_u32(&mut self) -> u32 {
+            unreachable!("FailingReseeder is only used via try_fill_bytes")
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            unreachable!("FailingReseeder is only used via try_fill_bytes")
+        }
+
+        fn fill_bytes(&mut self, _dest: &mut [u8]) {
+            unreachable!("FailingReseeder is only used via try_fill_bytes")
+        }
+
+        fn try_fill_bytes(&mut self, _dest: &mut [u8]) -> Result<(), Error> {
+            Err(Error::new(ErrorKind::Unavailable, "no entropy available"))
+        }
+    }
+
+    #[test]
+    fn reseeding_pcg_reseed_failure_leaves_inner_rng_untouched() {
+        let inner = PcgRng::from_seed(seed16(1, 2));
+        let mut rng = ReseedingPcg::new(inner.clone(), 4, FailingReseeder);
+        let mut expected = inner;
+
+        // The threshold (4 bytes) is crossed by the first call, triggering a reseed attempt that
+        // fails; the inner `PcgRng` must be left exactly as it was and keep advancing normally.
+        assert_eq!(rng.next_u32(), expected.next_u32());
+        assert_eq!(rng.next_u32(), expected.next_u32());
+    }
+
+    #[test]
+    fn reseeding_pcg_fill_bytes_matches_inner() {
+        let inner = PcgRng::from_seed(seed16(1, 2));
+        let mut rng = ReseedingPcg::new(inner.clone(), 1_000_000, CountingReseeder { next_byte: 0 });
+        let mut via_inner = inner;
+
+        let mut dest = [0u8; 24];
+        rng.fill_bytes(&mut dest);
+
+        for chunk in dest.chunks(8) {
+            assert_eq!(chunk, &via_inner.next_u64().to_le_bytes());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_reader_matches_from_seed() {
+        let seed = seed16(42, 54);
+        let mut rng = PcgRng::from_reader(&mut &seed[..]).unwrap();
+        let mut expected = PcgRng::from_seed(seed);
+
+        assert_eq!(rng.next_u32(), expected.next_u32());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn from_reader_reports_short_read() {
+        let short = [0u8; 8];
+        assert!(PcgRng::from_reader(&mut &short[..]).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn read_rng_matches_bytes() {
+        let data = [1u8, 2, 3, 4, 5, 6, 7, 8];
+        let mut rng = ReadRng::new(&data[..]);
+
+        let mut dest = [0u8; 8];
+        rng.fill_bytes(&mut dest);
+
+        assert_eq!(dest, data);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic]
+    fn read_rng_panics_on_insufficient_bytes() {
+        let mut rng = ReadRng::new(&[][..]);
+        let mut dest = [0u8; 3];
+        rng.fill_bytes(&mut dest);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn read_rng_try_fill_bytes_reports_short_read() {
+        let mut rng = ReadRng::new(&[1u8, 2][..]);
+        let mut dest = [0u8; 4];
+
+        assert!(rng.try_fill_bytes(&mut dest).is_err());
     }
 }